@@ -0,0 +1,63 @@
+// Copyright 2019 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The fact types shared between a frontend (e.g. rustc) and polonius-engine.
+
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// A small index type used to represent origins, loans, points, variables
+/// and paths.
+pub trait Atom:
+    From<usize> + Into<usize> + Copy + Clone + Debug + Eq + Ord + Hash + 'static
+{
+    fn index(self) -> usize;
+}
+
+/// The associated types that make up the facts consumed by polonius-engine.
+pub trait FactTypes: Copy + Clone + Debug {
+    type Origin: Atom;
+    type Loan: Atom;
+    type Point: Atom;
+    type Variable: Atom;
+    type Path: Atom;
+}
+
+/// The input facts a polonius analysis runs over.
+#[derive(Clone, Debug)]
+pub struct AllFacts<T: FactTypes> {
+    pub cfg_edge: Vec<(T::Point, T::Point)>,
+    // The unwind/cleanup edges of the CFG, kept apart from `cfg_edge` so
+    // drop-liveness can walk them without ordinary liveness doing the same.
+    pub cfg_unwind_edge: Vec<(T::Point, T::Point)>,
+    pub var_used: Vec<(T::Variable, T::Point)>,
+    pub var_defined: Vec<(T::Variable, T::Point)>,
+    pub var_drop_used: Vec<(T::Variable, T::Point)>,
+    pub var_uses_region: Vec<(T::Variable, T::Origin)>,
+    pub var_drops_region: Vec<(T::Variable, T::Origin)>,
+    pub var_maybe_initialized_on_exit: Vec<(T::Variable, T::Point)>,
+    pub universal_region: Vec<T::Origin>,
+}
+
+impl<T: FactTypes> Default for AllFacts<T> {
+    fn default() -> Self {
+        AllFacts {
+            cfg_edge: Vec::default(),
+            cfg_unwind_edge: Vec::default(),
+            var_used: Vec::default(),
+            var_defined: Vec::default(),
+            var_drop_used: Vec::default(),
+            var_uses_region: Vec::default(),
+            var_drops_region: Vec::default(),
+            var_maybe_initialized_on_exit: Vec::default(),
+            universal_region: Vec::default(),
+        }
+    }
+}
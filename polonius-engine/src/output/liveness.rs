@@ -10,10 +10,10 @@
 
 //! An implementation of the origin liveness calculation logic
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::time::Instant;
 
-use crate::output::Output;
+use crate::output::{Algorithm, Output};
 use facts::FactTypes;
 
 use datafrog::{Iteration, Relation, RelationLeaper};
@@ -25,19 +25,78 @@ pub(super) fn compute_live_regions<T: FactTypes>(
     var_uses_region: Vec<(T::Variable, T::Origin)>,
     var_drops_region: Vec<(T::Variable, T::Origin)>,
     cfg_edge: &[(T::Point, T::Point)],
+    cfg_unwind_edge: &[(T::Point, T::Point)],
     var_maybe_initialized_on_exit: Vec<(T::Variable, T::Point)>,
+    algorithm: Algorithm,
     output: &mut Output<T>,
 ) -> Vec<(T::Origin, T::Point)> {
     debug!("compute_liveness()");
     let computation_start = Instant::now();
+
+    // Reverse-postorder numbering of the points, also exposed on `Output`.
+    let rpo_rank = compute_rpo_rank::<T>(cfg_edge, cfg_unwind_edge);
+    output.rpo_rank = rpo_rank.clone();
+
+    let region_live_at = match algorithm {
+        Algorithm::Inverted => compute_live_regions_inverted(
+            var_used,
+            var_drop_used,
+            var_defined,
+            var_uses_region,
+            var_drops_region,
+            cfg_edge,
+            cfg_unwind_edge,
+            var_maybe_initialized_on_exit,
+            output,
+        ),
+        _ => compute_live_regions_fixpoint(
+            var_used,
+            var_drop_used,
+            var_defined,
+            var_uses_region,
+            var_drops_region,
+            cfg_edge,
+            cfg_unwind_edge,
+            var_maybe_initialized_on_exit,
+            output,
+        ),
+    };
+
+    info!(
+        "compute_liveness() completed: {} tuples, {:?}",
+        region_live_at.len(),
+        computation_start.elapsed()
+    );
+
+    region_live_at
+}
+
+// The original fixpoint: propagates `var_live`/`var_drop_live` backward
+// across `cfg_edge_reverse_rel` for all variables at once.
+fn compute_live_regions_fixpoint<T: FactTypes>(
+    var_used: Vec<(T::Variable, T::Point)>,
+    var_drop_used: Vec<(T::Variable, T::Point)>,
+    var_defined: Vec<(T::Variable, T::Point)>,
+    var_uses_region: Vec<(T::Variable, T::Origin)>,
+    var_drops_region: Vec<(T::Variable, T::Origin)>,
+    cfg_edge: &[(T::Point, T::Point)],
+    cfg_unwind_edge: &[(T::Point, T::Point)],
+    var_maybe_initialized_on_exit: Vec<(T::Variable, T::Point)>,
+    output: &mut Output<T>,
+) -> Vec<(T::Origin, T::Point)> {
     let mut iteration = Iteration::new();
 
     // Relations
     let var_defined_rel: Relation<(T::Variable, T::Point)> = var_defined.into();
-    let cfg_edge_rel: Relation<(T::Point, T::Point)> =
-        cfg_edge.iter().map(|(p, q)| (*p, *q)).collect();
     let cfg_edge_reverse_rel: Relation<(T::Point, T::Point)> =
         cfg_edge.iter().map(|(p, q)| (*q, *p)).collect();
+    // Unwind/cleanup edges (the `unwind` targets on `Drop`, `DropAndReplace`,
+    // `Call`, `Assert`, and `FalseUnwind` terminators) are kept apart from
+    // ordinary successor edges: a destructor that only runs on the unwind
+    // path must keep its region live along that path, even though nothing
+    // else about the point is a normal successor of it.
+    let cfg_unwind_edge_reverse_rel: Relation<(T::Point, T::Point)> =
+        cfg_unwind_edge.iter().map(|(p, q)| (*q, *p)).collect();
     let var_uses_region_rel: Relation<(T::Variable, T::Origin)> = var_uses_region.into();
     let var_drops_region_rel: Relation<(T::Variable, T::Origin)> = var_drops_region.into();
     let var_maybe_initialized_on_exit_rel: Relation<(T::Variable, T::Point)> =
@@ -63,6 +122,8 @@ pub(super) fn compute_live_regions<T: FactTypes>(
     // var_maybe_initialized_on_entry(V, Q) :-
     //     var_maybe_initialized_on_exit(V, P),
     //     cfg_edge(P, Q).
+    let cfg_edge_rel: Relation<(T::Point, T::Point)> =
+        cfg_edge.iter().map(|(p, q)| (*p, *q)).collect();
     let var_maybe_initialized_on_entry = Relation::from_leapjoin(
         &var_maybe_initialized_on_exit_rel,
         cfg_edge_rel.extend_with(|&(_v, p)| p),
@@ -121,16 +182,29 @@ pub(super) fn compute_live_regions<T: FactTypes>(
             ),
             |&(v, _q), &p| (v, p),
         );
+
+        // var_drop_live(V, P) :-
+        //     var_drop_live(V, Q),
+        //     cfg_unwind_edge(P, Q),
+        //     !var_defined(V, P),
+        //     var_maybe_initialized_on_exit(V, P).
+        //
+        // Same as the rule above, but walking cleanup edges instead of
+        // ordinary successor edges, so a drop that only runs on the unwind
+        // path still keeps its region live there.
+        var_drop_live_var.from_leapjoin(
+            &var_drop_live_var,
+            (
+                var_defined_rel.extend_anti(|&(v, _q)| v),
+                cfg_unwind_edge_reverse_rel.extend_with(|&(_v, q)| q),
+                var_maybe_initialized_on_exit_rel.extend_with(|&(v, _q)| v),
+            ),
+            |&(v, _q), &p| (v, p),
+        );
     }
 
     let region_live_at_rel = region_live_at_var.complete();
 
-    info!(
-        "compute_liveness() completed: {} tuples, {:?}",
-        region_live_at_rel.len(),
-        computation_start.elapsed()
-    );
-
     if output.dump_enabled {
         let var_drop_live_at = var_drop_live_var.complete();
         for &(var, location) in &var_drop_live_at.elements {
@@ -157,19 +231,383 @@ pub(super) fn compute_live_regions<T: FactTypes>(
         .collect()
 }
 
+// The inverted mode: walk backward from each variable's uses individually,
+// over a predecessor adjacency map, instead of running one fixpoint over
+// all variables at once.
+fn compute_live_regions_inverted<T: FactTypes>(
+    var_used: Vec<(T::Variable, T::Point)>,
+    var_drop_used: Vec<(T::Variable, T::Point)>,
+    var_defined: Vec<(T::Variable, T::Point)>,
+    var_uses_region: Vec<(T::Variable, T::Origin)>,
+    var_drops_region: Vec<(T::Variable, T::Origin)>,
+    cfg_edge: &[(T::Point, T::Point)],
+    cfg_unwind_edge: &[(T::Point, T::Point)],
+    var_maybe_initialized_on_exit: Vec<(T::Variable, T::Point)>,
+    output: &mut Output<T>,
+) -> Vec<(T::Origin, T::Point)> {
+    let mut predecessors: BTreeMap<T::Point, Vec<T::Point>> = BTreeMap::new();
+    for &(p, q) in cfg_edge {
+        predecessors.entry(q).or_insert_with(Vec::new).push(p);
+    }
+
+    // Drop-liveness additionally walks cleanup edges, so a destructor that
+    // only runs on the unwind path still keeps its region live there.
+    let mut drop_predecessors = predecessors.clone();
+    for &(p, q) in cfg_unwind_edge {
+        drop_predecessors.entry(q).or_insert_with(Vec::new).push(p);
+    }
+
+    let var_defined_at: BTreeSet<(T::Variable, T::Point)> = var_defined.into_iter().collect();
+    let var_maybe_initialized_on_exit: BTreeSet<(T::Variable, T::Point)> =
+        var_maybe_initialized_on_exit.into_iter().collect();
+    let var_maybe_initialized_on_entry =
+        compute_maybe_initialized_on_entry::<T>(&var_maybe_initialized_on_exit, cfg_edge);
+
+    // var_live(V, P) :-
+    //     var_used(V, P).
+    // var_live(V, P) :-
+    //     var_live(V, Q),
+    //     cfg_edge(P, Q),
+    //     !var_defined(V, P).
+    let var_live_at = walk_live_backward(&var_used, &predecessors, |v, p| {
+        !var_defined_at.contains(&(v, p))
+    });
+
+    // var_drop_live(V, P) :-
+    //     var_drop_used(V, P),
+    //     var_maybe_initialized_on_entry(V, P).
+    // var_drop_live(V, P) :-
+    //     var_drop_live(V, Q),
+    //     (cfg_edge(P, Q) OR cfg_unwind_edge(P, Q)),
+    //     !var_defined(V, P),
+    //     var_maybe_initialized_on_exit(V, P).
+    let drop_seeds: Vec<(T::Variable, T::Point)> = var_drop_used
+        .into_iter()
+        .filter(|&(v, p)| var_maybe_initialized_on_entry.contains(&(v, p)))
+        .collect();
+    let var_drop_live_at = walk_live_backward(&drop_seeds, &drop_predecessors, |v, p| {
+        !var_defined_at.contains(&(v, p)) && var_maybe_initialized_on_exit.contains(&(v, p))
+    });
+
+    let mut regions_used_by: BTreeMap<T::Variable, Vec<T::Origin>> = BTreeMap::new();
+    for (v, r) in var_uses_region {
+        regions_used_by.entry(v).or_insert_with(Vec::new).push(r);
+    }
+    let mut regions_dropped_by: BTreeMap<T::Variable, Vec<T::Origin>> = BTreeMap::new();
+    for (v, r) in var_drops_region {
+        regions_dropped_by.entry(v).or_insert_with(Vec::new).push(r);
+    }
+
+    // region_live_at(R, P) :-
+    //   var_live(V, P),
+    //   var_uses_region(V, R).
+    //
+    // region_live_at(R, P) :-
+    //   var_drop_live(V, P),
+    //   var_drops_region(V, R).
+    let mut region_live_at: BTreeSet<(T::Origin, T::Point)> = BTreeSet::new();
+    for &(v, p) in &var_live_at {
+        if let Some(regions) = regions_used_by.get(&v) {
+            region_live_at.extend(regions.iter().map(|&r| (r, p)));
+        }
+    }
+    for &(v, p) in &var_drop_live_at {
+        if let Some(regions) = regions_dropped_by.get(&v) {
+            region_live_at.extend(regions.iter().map(|&r| (r, p)));
+        }
+    }
+
+    if output.dump_enabled {
+        for &(var, location) in &var_drop_live_at {
+            output
+                .var_drop_live_at
+                .entry(location)
+                .or_insert_with(Vec::new)
+                .push(var);
+        }
+
+        for &(var, location) in &var_live_at {
+            output
+                .var_live_at
+                .entry(location)
+                .or_insert_with(Vec::new)
+                .push(var);
+        }
+    }
+
+    region_live_at.into_iter().collect()
+}
+
+// Walks the CFG backward from `seeds`, marking `(V, P)` live whenever `P` is
+// a predecessor of an already-live `(V, Q)` and `can_propagate(V, P)` holds.
+// `live.insert` gates every push, so each `(V, P)` pair enters the worklist
+// at most once; a plain FIFO is as good as any other order here.
+fn walk_live_backward<T: FactTypes>(
+    seeds: &[(T::Variable, T::Point)],
+    predecessors: &BTreeMap<T::Point, Vec<T::Point>>,
+    can_propagate: impl Fn(T::Variable, T::Point) -> bool,
+) -> BTreeSet<(T::Variable, T::Point)> {
+    let mut live: BTreeSet<(T::Variable, T::Point)> = seeds.iter().cloned().collect();
+    let mut worklist: VecDeque<(T::Variable, T::Point)> = live.iter().cloned().collect();
+
+    while let Some((v, q)) = worklist.pop_front() {
+        if let Some(preds) = predecessors.get(&q) {
+            for &p in preds {
+                if can_propagate(v, p) && live.insert((v, p)) {
+                    worklist.push_back((v, p));
+                }
+            }
+        }
+    }
+
+    live
+}
+
+// var_maybe_initialized_on_entry(V, Q) :-
+//     var_maybe_initialized_on_exit(V, P),
+//     cfg_edge(P, Q).
+//
+// Shared by `compute_live_regions_inverted` and `explain_region_live_at` so
+// the drop-liveness base case (a drop-use only seeds liveness if the
+// variable is maybe-initialized on entry) can't drift between the two.
+fn compute_maybe_initialized_on_entry<T: FactTypes>(
+    var_maybe_initialized_on_exit: &BTreeSet<(T::Variable, T::Point)>,
+    cfg_edge: &[(T::Point, T::Point)],
+) -> BTreeSet<(T::Variable, T::Point)> {
+    let mut initialized_on_exit_at: BTreeMap<T::Point, Vec<T::Variable>> = BTreeMap::new();
+    for &(v, p) in var_maybe_initialized_on_exit {
+        initialized_on_exit_at
+            .entry(p)
+            .or_insert_with(Vec::new)
+            .push(v);
+    }
+
+    let mut entry = BTreeSet::new();
+    for &(p, q) in cfg_edge {
+        if let Some(vars) = initialized_on_exit_at.get(&p) {
+            entry.extend(vars.iter().map(|&v| (v, q)));
+        }
+    }
+    entry
+}
+
+// Reverse-postorder numbering of the points reachable from `cfg_edge` and
+// `cfg_unwind_edge`: lower ranks come earlier in a forward traversal.
+fn compute_rpo_rank<T: FactTypes>(
+    cfg_edge: &[(T::Point, T::Point)],
+    cfg_unwind_edge: &[(T::Point, T::Point)],
+) -> BTreeMap<T::Point, usize> {
+    let mut successors: BTreeMap<T::Point, Vec<T::Point>> = BTreeMap::new();
+    let mut all_points: BTreeSet<T::Point> = BTreeSet::new();
+    for &(p, q) in cfg_edge.iter().chain(cfg_unwind_edge.iter()) {
+        successors.entry(p).or_insert_with(Vec::new).push(q);
+        all_points.insert(p);
+        all_points.insert(q);
+    }
+
+    let mut postorder: Vec<T::Point> = Vec::with_capacity(all_points.len());
+    let mut visited: BTreeSet<T::Point> = BTreeSet::new();
+
+    for &start in &all_points {
+        if !visited.insert(start) {
+            continue;
+        }
+
+        // Iterative post-order DFS, to avoid blowing the stack on deep CFGs.
+        let mut stack: Vec<(T::Point, usize)> = vec![(start, 0)];
+        while let Some(&mut (point, ref mut next_child)) = stack.last_mut() {
+            match successors
+                .get(&point)
+                .and_then(|succs| succs.get(*next_child))
+            {
+                Some(&child) => {
+                    *next_child += 1;
+                    if visited.insert(child) {
+                        stack.push((child, 0));
+                    }
+                }
+                None => {
+                    postorder.push(point);
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    // Reverse the post-order to get the reverse-postorder ranks: the last
+    // point to finish (highest post-order position, the root of the last
+    // DFS tree started) gets rank 0.
+    postorder
+        .into_iter()
+        .rev()
+        .enumerate()
+        .map(|(rank, point)| (point, rank))
+        .collect()
+}
+
+/// A witness explaining why `region_live_at(R, P)` holds: the variable that
+/// makes `R` live at `P`, whether via a use or a drop-use, and the path.
+pub struct LivenessPath<T: FactTypes> {
+    /// The variable whose liveness makes the region live.
+    pub variable: T::Variable,
+    /// Whether `variable` is live via a drop-use rather than a use.
+    pub drop: bool,
+    /// Points visited along the path, from the queried point to the use.
+    pub path: Vec<T::Point>,
+}
+
+// Reconstructs, on demand, why `region_live_at(R, P)` holds, by replaying
+// the backward walk for just the queried `(R, P)` pair rather than
+// recording provenance for every tuple during the fixpoint.
+pub(super) fn explain_region_live_at<T: FactTypes>(
+    region: T::Origin,
+    point: T::Point,
+    var_used: &[(T::Variable, T::Point)],
+    var_drop_used: &[(T::Variable, T::Point)],
+    var_defined: &[(T::Variable, T::Point)],
+    var_uses_region: &[(T::Variable, T::Origin)],
+    var_drops_region: &[(T::Variable, T::Origin)],
+    cfg_edge: &[(T::Point, T::Point)],
+    cfg_unwind_edge: &[(T::Point, T::Point)],
+    var_maybe_initialized_on_exit: &[(T::Variable, T::Point)],
+) -> Option<LivenessPath<T>> {
+    let var_defined_at: BTreeSet<(T::Variable, T::Point)> = var_defined.iter().cloned().collect();
+    let var_maybe_initialized_on_exit: BTreeSet<(T::Variable, T::Point)> =
+        var_maybe_initialized_on_exit.iter().cloned().collect();
+    let var_maybe_initialized_on_entry =
+        compute_maybe_initialized_on_entry::<T>(&var_maybe_initialized_on_exit, cfg_edge);
+    let var_used_at: BTreeSet<(T::Variable, T::Point)> = var_used.iter().cloned().collect();
+    // A drop-use only makes its variable drop-live if the variable is also
+    // maybe-initialized on entry to that point (see the `var_drop_live` base
+    // case in `compute_live_regions_fixpoint` and
+    // `compute_live_regions_inverted`).
+    let var_drop_used_at: BTreeSet<(T::Variable, T::Point)> = var_drop_used
+        .iter()
+        .cloned()
+        .filter(|vp| var_maybe_initialized_on_entry.contains(vp))
+        .collect();
+
+    let candidates = var_uses_region
+        .iter()
+        .filter(|&&(_, r)| r == region)
+        .map(|&(v, _)| (v, false))
+        .chain(
+            var_drops_region
+                .iter()
+                .filter(|&&(_, r)| r == region)
+                .map(|&(v, _)| (v, true)),
+        );
+
+    for (variable, drop) in candidates {
+        let used_at = if drop {
+            &var_drop_used_at
+        } else {
+            &var_used_at
+        };
+        let maybe_initialized_on_exit = if drop {
+            Some(&var_maybe_initialized_on_exit)
+        } else {
+            None
+        };
+
+        if let Some(path) = find_live_path::<T>(
+            variable,
+            point,
+            used_at,
+            &var_defined_at,
+            maybe_initialized_on_exit,
+            cfg_edge,
+            cfg_unwind_edge,
+        ) {
+            return Some(LivenessPath {
+                variable,
+                drop,
+                path,
+            });
+        }
+    }
+
+    None
+}
+
+// Forward BFS from `start` for the shortest path to a point where `variable`
+// is used (or drop-used); cleanup edges are only followed when
+// `maybe_initialized_on_exit` is given (the drop-liveness case).
+fn find_live_path<T: FactTypes>(
+    variable: T::Variable,
+    start: T::Point,
+    used_at: &BTreeSet<(T::Variable, T::Point)>,
+    defined_at: &BTreeSet<(T::Variable, T::Point)>,
+    maybe_initialized_on_exit: Option<&BTreeSet<(T::Variable, T::Point)>>,
+    cfg_edge: &[(T::Point, T::Point)],
+    cfg_unwind_edge: &[(T::Point, T::Point)],
+) -> Option<Vec<T::Point>> {
+    let mut successors: BTreeMap<T::Point, Vec<T::Point>> = BTreeMap::new();
+    for &(p, q) in cfg_edge {
+        successors.entry(p).or_insert_with(Vec::new).push(q);
+    }
+    if maybe_initialized_on_exit.is_some() {
+        for &(p, q) in cfg_unwind_edge {
+            successors.entry(p).or_insert_with(Vec::new).push(q);
+        }
+    }
+
+    let mut visited: BTreeSet<T::Point> = BTreeSet::new();
+    visited.insert(start);
+    let mut worklist: VecDeque<Vec<T::Point>> = VecDeque::new();
+    worklist.push_back(vec![start]);
+
+    while let Some(path) = worklist.pop_front() {
+        let current = *path.last().expect("path is never empty");
+
+        if used_at.contains(&(variable, current)) {
+            return Some(path);
+        }
+
+        if defined_at.contains(&(variable, current)) {
+            continue;
+        }
+        if let Some(initialized) = maybe_initialized_on_exit {
+            if !initialized.contains(&(variable, current)) {
+                continue;
+            }
+        }
+
+        if let Some(succs) = successors.get(&current) {
+            for &next in succs {
+                if visited.insert(next) {
+                    let mut next_path = path.clone();
+                    next_path.push(next);
+                    worklist.push_back(next_path);
+                }
+            }
+        }
+    }
+
+    None
+}
+
 pub(super) fn make_universal_region_live<T: FactTypes>(
     region_live_at: &mut Vec<(T::Origin, T::Point)>,
     cfg_edge: &[(T::Point, T::Point)],
+    cfg_unwind_edge: &[(T::Point, T::Point)],
+    rpo_rank: &BTreeMap<T::Point, usize>,
     universal_region: Vec<T::Origin>,
 ) {
     debug!("make_universal_regions_live()");
 
     let all_points: BTreeSet<T::Point> = cfg_edge
         .iter()
-        .map(|&(p, _)| p)
-        .chain(cfg_edge.iter().map(|&(_, q)| q))
+        .chain(cfg_unwind_edge.iter())
+        .flat_map(|&(p, q)| [p, q])
         .collect();
 
+    // Enumerate points in reverse-postorder rather than the `BTreeSet`'s
+    // arbitrary point order, so this is deterministic with respect to the
+    // CFG's structure rather than to `T::Point`'s `Ord` impl.
+    let mut all_points: Vec<T::Point> = all_points.into_iter().collect();
+    all_points.sort_by_key(|p| (rpo_rank.get(p).copied().unwrap_or(usize::MAX), *p));
+
     region_live_at.reserve(universal_region.len() * all_points.len());
     for &r in &universal_region {
         for &p in &all_points {
@@ -186,7 +624,9 @@ pub(super) fn init_region_live_at<T: FactTypes>(
     var_drops_region: Vec<(T::Variable, T::Origin)>,
     var_maybe_initialized_on_exit: Vec<(T::Variable, T::Point)>,
     cfg_edge: &[(T::Point, T::Point)],
+    cfg_unwind_edge: &[(T::Point, T::Point)],
     universal_region: Vec<T::Origin>,
+    algorithm: Algorithm,
     output: &mut Output<T>,
 ) -> Vec<(T::Origin, T::Point)> {
     debug!("init_region_live_at()");
@@ -197,11 +637,190 @@ pub(super) fn init_region_live_at<T: FactTypes>(
         var_uses_region,
         var_drops_region,
         cfg_edge,
+        cfg_unwind_edge,
         var_maybe_initialized_on_exit,
+        algorithm,
         output,
     );
 
-    make_universal_region_live::<T>(&mut region_live_at, cfg_edge, universal_region);
+    make_universal_region_live::<T>(
+        &mut region_live_at,
+        cfg_edge,
+        cfg_unwind_edge,
+        &output.rpo_rank,
+        universal_region,
+    );
 
     region_live_at
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use facts::Atom;
+
+    #[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Hash)]
+    struct TestAtom(usize);
+
+    impl From<usize> for TestAtom {
+        fn from(index: usize) -> Self {
+            TestAtom(index)
+        }
+    }
+
+    impl Into<usize> for TestAtom {
+        fn into(self) -> usize {
+            self.0
+        }
+    }
+
+    impl Atom for TestAtom {
+        fn index(self) -> usize {
+            self.0
+        }
+    }
+
+    #[derive(Copy, Clone, Debug)]
+    struct TestFacts;
+
+    impl FactTypes for TestFacts {
+        type Origin = TestAtom;
+        type Loan = TestAtom;
+        type Point = TestAtom;
+        type Variable = TestAtom;
+        type Path = TestAtom;
+    }
+
+    fn atom(index: usize) -> TestAtom {
+        TestAtom(index)
+    }
+
+    fn empty_output() -> Output<TestFacts> {
+        Output {
+            dump_enabled: false,
+            region_live_at: BTreeMap::new(),
+            var_live_at: BTreeMap::new(),
+            var_drop_live_at: BTreeMap::new(),
+            rpo_rank: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn compute_rpo_rank_orders_points_before_their_successors() {
+        let cfg_edge = vec![(atom(0), atom(1)), (atom(1), atom(2))];
+        let rank = compute_rpo_rank::<TestFacts>(&cfg_edge, &[]);
+
+        assert!(rank[&atom(0)] < rank[&atom(1)]);
+        assert!(rank[&atom(1)] < rank[&atom(2)]);
+    }
+
+    #[test]
+    fn compute_rpo_rank_includes_unwind_successors() {
+        let cfg_edge = vec![(atom(0), atom(1))];
+        let cfg_unwind_edge = vec![(atom(0), atom(2))];
+        let rank = compute_rpo_rank::<TestFacts>(&cfg_edge, &cfg_unwind_edge);
+
+        assert!(rank.contains_key(&atom(2)));
+        assert!(rank[&atom(0)] < rank[&atom(2)]);
+    }
+
+    #[test]
+    fn fixpoint_and_inverted_agree_on_unwind_gated_drop_liveness() {
+        // 0 -> 1 ordinarily; 2 -> 1 only on the unwind/cleanup path.
+        // Variable 0 is drop-used at 1 and maybe-initialized-on-exit at both
+        // 0 and 2, so its region should end up live at all three points:
+        // at 1 directly, and backward across both the ordinary edge (into
+        // 0) and the unwind edge (into 2).
+        let cfg_edge = vec![(atom(0), atom(1))];
+        let cfg_unwind_edge = vec![(atom(2), atom(1))];
+        let var_drop_used = vec![(atom(0), atom(1))];
+        let var_drops_region = vec![(atom(0), atom(10))];
+        let var_maybe_initialized_on_exit = vec![(atom(0), atom(0)), (atom(0), atom(2))];
+
+        let mut fixpoint = compute_live_regions_fixpoint::<TestFacts>(
+            vec![],
+            var_drop_used.clone(),
+            vec![],
+            vec![],
+            var_drops_region.clone(),
+            &cfg_edge,
+            &cfg_unwind_edge,
+            var_maybe_initialized_on_exit.clone(),
+            &mut empty_output(),
+        );
+        fixpoint.sort();
+
+        let mut inverted = compute_live_regions_inverted::<TestFacts>(
+            vec![],
+            var_drop_used,
+            vec![],
+            vec![],
+            var_drops_region,
+            &cfg_edge,
+            &cfg_unwind_edge,
+            var_maybe_initialized_on_exit,
+            &mut empty_output(),
+        );
+        inverted.sort();
+
+        assert_eq!(fixpoint, inverted);
+        assert_eq!(
+            fixpoint,
+            vec![
+                (atom(10), atom(0)),
+                (atom(10), atom(1)),
+                (atom(10), atom(2))
+            ]
+        );
+    }
+
+    #[test]
+    fn explain_region_live_at_finds_the_use_path() {
+        let cfg_edge = vec![(atom(0), atom(1)), (atom(1), atom(2))];
+        let var_used = vec![(atom(0), atom(2))];
+        let var_uses_region = vec![(atom(0), atom(10))];
+
+        let path = explain_region_live_at::<TestFacts>(
+            atom(10),
+            atom(0),
+            &var_used,
+            &[],
+            &[],
+            &var_uses_region,
+            &[],
+            &cfg_edge,
+            &[],
+            &[],
+        )
+        .expect("region 10 should be live at point 0 via variable 0's use at point 2");
+
+        assert_eq!(path.variable, atom(0));
+        assert!(!path.drop);
+        assert_eq!(path.path, vec![atom(0), atom(1), atom(2)]);
+    }
+
+    #[test]
+    fn explain_region_live_at_stops_at_a_redefinition() {
+        // Variable 0 is redefined at point 1 before its use at point 2, so
+        // region 10 is not live at point 0 through that variable.
+        let cfg_edge = vec![(atom(0), atom(1)), (atom(1), atom(2))];
+        let var_used = vec![(atom(0), atom(2))];
+        let var_defined = vec![(atom(0), atom(1))];
+        let var_uses_region = vec![(atom(0), atom(10))];
+
+        let path = explain_region_live_at::<TestFacts>(
+            atom(10),
+            atom(0),
+            &var_used,
+            &[],
+            &var_defined,
+            &var_uses_region,
+            &[],
+            &cfg_edge,
+            &[],
+            &[],
+        );
+
+        assert!(path.is_none());
+    }
+}
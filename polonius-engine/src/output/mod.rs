@@ -0,0 +1,113 @@
+// Copyright 2019 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The output of a polonius analysis.
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use facts::{AllFacts, FactTypes};
+
+mod liveness;
+
+pub use self::liveness::LivenessPath;
+
+/// The liveness algorithm used to compute `region_live_at`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    /// A single Datafrog fixpoint over all variables at once.
+    Naive,
+    /// A backward walk from each variable's uses, run individually.
+    Inverted,
+}
+
+impl Algorithm {
+    pub const OPTIONS: &'static [&'static str] = &["Naive", "Inverted"];
+}
+
+impl FromStr for Algorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Naive" => Ok(Algorithm::Naive),
+            "Inverted" => Ok(Algorithm::Inverted),
+            _ => Err(format!("unrecognized algorithm `{}`", s)),
+        }
+    }
+}
+
+/// The result of running a polonius analysis.
+#[derive(Clone, Debug)]
+pub struct Output<T: FactTypes> {
+    pub dump_enabled: bool,
+
+    pub region_live_at: BTreeMap<T::Point, Vec<T::Origin>>,
+    pub var_live_at: BTreeMap<T::Point, Vec<T::Variable>>,
+    pub var_drop_live_at: BTreeMap<T::Point, Vec<T::Variable>>,
+    pub rpo_rank: BTreeMap<T::Point, usize>,
+}
+
+impl<T: FactTypes> Output<T> {
+    pub fn compute(all_facts: AllFacts<T>, algorithm: Algorithm, dump_enabled: bool) -> Self {
+        let mut result = Output {
+            dump_enabled,
+            region_live_at: BTreeMap::new(),
+            var_live_at: BTreeMap::new(),
+            var_drop_live_at: BTreeMap::new(),
+            rpo_rank: BTreeMap::new(),
+        };
+
+        let region_live_at = liveness::init_region_live_at(
+            all_facts.var_used,
+            all_facts.var_drop_used,
+            all_facts.var_defined,
+            all_facts.var_uses_region,
+            all_facts.var_drops_region,
+            all_facts.var_maybe_initialized_on_exit,
+            &all_facts.cfg_edge,
+            &all_facts.cfg_unwind_edge,
+            all_facts.universal_region,
+            algorithm,
+            &mut result,
+        );
+
+        for (origin, point) in region_live_at {
+            result
+                .region_live_at
+                .entry(point)
+                .or_insert_with(Vec::new)
+                .push(origin);
+        }
+
+        result
+    }
+
+    /// Explains, on demand, why `region_live_at(region, point)` holds.
+    pub fn explain_region_live_at(
+        &self,
+        all_facts: &AllFacts<T>,
+        region: T::Origin,
+        point: T::Point,
+    ) -> Option<LivenessPath<T>> {
+        liveness::explain_region_live_at(
+            region,
+            point,
+            &all_facts.var_used,
+            &all_facts.var_drop_used,
+            &all_facts.var_defined,
+            &all_facts.var_uses_region,
+            &all_facts.var_drops_region,
+            &all_facts.cfg_edge,
+            &all_facts.cfg_unwind_edge,
+            &all_facts.var_maybe_initialized_on_exit,
+        )
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2019 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Interns the string identifiers used in NLL fact files into `Atom`
+//! indices, and defines the concrete `FactTypes` used by this crate.
+
+use facts::{Atom, FactTypes};
+use std::collections::HashMap;
+
+#[derive(Copy, Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd, Hash)]
+pub struct Index(usize);
+
+impl From<usize> for Index {
+    fn from(index: usize) -> Self {
+        Index(index)
+    }
+}
+
+impl Into<usize> for Index {
+    fn into(self) -> usize {
+        self.0
+    }
+}
+
+impl Atom for Index {
+    fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct LocalFacts;
+
+impl FactTypes for LocalFacts {
+    type Origin = Index;
+    type Loan = Index;
+    type Point = Index;
+    type Variable = Index;
+    type Path = Index;
+}
+
+#[derive(Default)]
+struct Interner {
+    indices: HashMap<String, Index>,
+}
+
+impl Interner {
+    fn intern(&mut self, value: &str) -> Index {
+        if let Some(&index) = self.indices.get(value) {
+            return index;
+        }
+
+        let index = Index(self.indices.len());
+        self.indices.insert(value.to_string(), index);
+        index
+    }
+}
+
+/// One interner per kind of fact-file column, so indices of one kind are
+/// never confused with indices of another.
+#[derive(Default)]
+pub struct InternerTables {
+    origins: Interner,
+    loans: Interner,
+    points: Interner,
+    variables: Interner,
+    paths: Interner,
+}
+
+impl InternerTables {
+    pub fn new() -> Self {
+        InternerTables::default()
+    }
+
+    pub fn origin(&mut self, value: &str) -> Index {
+        self.origins.intern(value)
+    }
+
+    pub fn loan(&mut self, value: &str) -> Index {
+        self.loans.intern(value)
+    }
+
+    pub fn point(&mut self, value: &str) -> Index {
+        self.points.intern(value)
+    }
+
+    pub fn variable(&mut self, value: &str) -> Index {
+        self.variables.intern(value)
+    }
+
+    pub fn path(&mut self, value: &str) -> Index {
+        self.paths.intern(value)
+    }
+}
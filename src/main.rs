@@ -0,0 +1,46 @@
+// Copyright 2019 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A small CLI driver that runs a polonius analysis over a directory of
+//! tab-delimited NLL facts.
+
+mod intern;
+mod tab_delim;
+#[cfg(test)]
+mod test;
+
+pub use polonius_engine::output;
+
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+use output::{Algorithm, Output};
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let facts_dir = match args.next() {
+        Some(dir) => PathBuf::from(dir),
+        None => {
+            eprintln!("usage: polonius <facts-dir> [algorithm]");
+            process::exit(1);
+        }
+    };
+    let algorithm = args
+        .next()
+        .map(|s| s.parse().expect("unrecognized algorithm"))
+        .unwrap_or(Algorithm::Naive);
+
+    let tables = &mut intern::InternerTables::new();
+    let all_facts =
+        tab_delim::load_tab_delimited_facts(tables, &facts_dir).expect("failed to load facts");
+    let result = Output::compute(all_facts, algorithm, false);
+    println!("{:#?}", result);
+}
@@ -0,0 +1,100 @@
+// Copyright 2019 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Loads `AllFacts` from a directory of tab-delimited `.facts` files, one
+//! per input relation. A missing file is treated as an empty relation.
+
+use crate::intern::{InternerTables, LocalFacts};
+use facts::AllFacts;
+use failure::Error;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+type Point = <LocalFacts as facts::FactTypes>::Point;
+type Variable = <LocalFacts as facts::FactTypes>::Variable;
+type Origin = <LocalFacts as facts::FactTypes>::Origin;
+
+pub fn load_tab_delimited_facts(
+    tables: &mut InternerTables,
+    facts_dir: &Path,
+) -> Result<AllFacts<LocalFacts>, Error> {
+    Ok(AllFacts {
+        cfg_edge: load_point_point(tables, facts_dir, "cfg_edge.facts")?,
+        cfg_unwind_edge: load_point_point(tables, facts_dir, "cfg_unwind_edge.facts")?,
+        var_used: load_var_point(tables, facts_dir, "var_used.facts")?,
+        var_defined: load_var_point(tables, facts_dir, "var_defined.facts")?,
+        var_drop_used: load_var_point(tables, facts_dir, "var_drop_used.facts")?,
+        var_uses_region: load_var_origin(tables, facts_dir, "var_uses_region.facts")?,
+        var_drops_region: load_var_origin(tables, facts_dir, "var_drops_region.facts")?,
+        var_maybe_initialized_on_exit: load_var_point(
+            tables,
+            facts_dir,
+            "var_maybe_initialized_on_exit.facts",
+        )?,
+        universal_region: load_origin(tables, facts_dir, "universal_region.facts")?,
+    })
+}
+
+fn rows(facts_dir: &Path, file_name: &str) -> Result<Vec<Vec<String>>, Error> {
+    let path = facts_dir.join(file_name);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    BufReader::new(File::open(&path)?)
+        .lines()
+        .map(|line| Ok(line?.split('\t').map(str::to_string).collect()))
+        .collect()
+}
+
+fn load_point_point(
+    tables: &mut InternerTables,
+    facts_dir: &Path,
+    file_name: &str,
+) -> Result<Vec<(Point, Point)>, Error> {
+    rows(facts_dir, file_name)?
+        .into_iter()
+        .map(|row| Ok((tables.point(&row[0]), tables.point(&row[1]))))
+        .collect()
+}
+
+fn load_var_point(
+    tables: &mut InternerTables,
+    facts_dir: &Path,
+    file_name: &str,
+) -> Result<Vec<(Variable, Point)>, Error> {
+    rows(facts_dir, file_name)?
+        .into_iter()
+        .map(|row| Ok((tables.variable(&row[0]), tables.point(&row[1]))))
+        .collect()
+}
+
+fn load_var_origin(
+    tables: &mut InternerTables,
+    facts_dir: &Path,
+    file_name: &str,
+) -> Result<Vec<(Variable, Origin)>, Error> {
+    rows(facts_dir, file_name)?
+        .into_iter()
+        .map(|row| Ok((tables.variable(&row[0]), tables.origin(&row[1]))))
+        .collect()
+}
+
+fn load_origin(
+    tables: &mut InternerTables,
+    facts_dir: &Path,
+    file_name: &str,
+) -> Result<Vec<Origin>, Error> {
+    rows(facts_dir, file_name)?
+        .into_iter()
+        .map(|row| Ok(tables.origin(&row[0])))
+        .collect()
+}
@@ -21,6 +21,29 @@ fn test_fn(dir_name: &str, fn_name: &str) -> Result<(), Error> {
     }
 }
 
+// The inverted walk is a hand-rolled restatement of the fixpoint's
+// semantics, so it must agree with it on every `region_live_at` fact.
+fn test_liveness_algorithms_agree(dir_name: &str, fn_name: &str) -> Result<(), Error> {
+    do catch {
+        let facts_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("inputs")
+            .join(dir_name)
+            .join("nll-facts")
+            .join(fn_name);
+        let tables = &mut intern::InternerTables::new();
+        let all_facts = tab_delim::load_tab_delimited_facts(tables, &facts_dir)?;
+
+        let naive = Output::compute(all_facts.clone(), Algorithm::Naive, false);
+        let inverted = Output::compute(all_facts, Algorithm::Inverted, false);
+
+        assert_eq!(
+            naive.region_live_at, inverted.region_live_at,
+            "Naive and Inverted disagree on region_live_at for {}/{}",
+            dir_name, fn_name
+        );
+    }
+}
+
 macro_rules! tests {
     ($($name:ident($dir:expr, $fn:expr),)*) => {
         $(
@@ -35,3 +58,8 @@ macro_rules! tests {
 tests! {
     issue_47680("issue-47680", "main"),
 }
+
+#[test]
+fn issue_47680_liveness_algorithms_agree() -> Result<(), Error> {
+    test_liveness_algorithms_agree("issue-47680", "main")
+}